@@ -1,7 +1,6 @@
-use crate::board::Grid;
-use crate::backtracking::{brute_force, SolveResult};
-use std::sync::{Arc, Mutex};
-use std::thread;
+use crate::board::{Cell, Colour, Grid, Point};
+use crate::backtracking::SolveResult;
+use crate::sat::HintSession;
 use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9,6 +8,81 @@ pub enum AppState {
     PuzzleSelection,
     Solving,
     ViewingSolution,
+    Benchmark,
+    Hinting,
+}
+
+/// One solver's result from a [`run_benchmark`] pass, ready to be turned
+/// into a `(label, value)` pair for the benchmark `BarChart`s.
+pub struct SolverBenchmark {
+    pub label: String,
+    pub cells_filled: u64,
+    pub millis: u64,
+}
+
+fn count_filled(grid: &Grid) -> u64 {
+    grid.cells
+        .iter()
+        .flatten()
+        .filter(|c| !matches!(c, Cell::Empty))
+        .count() as u64
+}
+
+/// Default location of the warm-start model, relative to the working
+/// directory the binary is run from. Overridable per-`App` (see
+/// [`App::model_path`]) so a Flow-Free-specific model other than this one
+/// can be swapped in without touching `run_benchmark`.
+pub const DEFAULT_MODEL_PATH: &str = "flowai/models/flow_free_warm_start.pt";
+
+/// Runs the backtracking, SAT, and warm-started SAT solvers against `grid`
+/// (A* and beam search are not included) and records each one's
+/// wall-clock time and cells filled. Free-standing (rather than a method on
+/// `App`) so it can run on a background thread and be handed back to
+/// [`App::apply_benchmark_done`] over a channel. `model_path` is the warm
+/// start model to try to load (see [`App::model_path`]); it's threaded in
+/// rather than hardcoded so callers can point the benchmark at a different
+/// model without changing this function.
+pub fn run_benchmark(grid: &Grid, model_path: &str) -> Vec<SolverBenchmark> {
+    use crate::backtracking::brute_force;
+    use crate::sat::solve_sat;
+
+    let mut results = Vec::with_capacity(3);
+
+    let mut backtracking_grid = grid.clone();
+    let start = Instant::now();
+    brute_force(&mut backtracking_grid);
+    results.push(SolverBenchmark {
+        label: "Backtracking".to_string(),
+        cells_filled: count_filled(&backtracking_grid),
+        millis: start.elapsed().as_millis() as u64,
+    });
+
+    let start = Instant::now();
+    let sat_solution = solve_sat(grid);
+    results.push(SolverBenchmark {
+        label: "SAT".to_string(),
+        cells_filled: sat_solution.as_ref().map(count_filled).unwrap_or(0),
+        millis: start.elapsed().as_millis() as u64,
+    });
+
+    let start = Instant::now();
+    let warm_start_solution = {
+        use crate::puzzle_ai::{warm_start_predictions, TorchModelBackend};
+        use crate::sat::solve_sat_with_warm_start;
+
+        let colours: Vec<_> = grid.get_endpoints().keys().cloned().collect();
+        let predictions = TorchModelBackend::load(model_path)
+            .map(|backend| warm_start_predictions(grid, &backend, &colours, 0.9))
+            .unwrap_or_default();
+        solve_sat_with_warm_start(grid, &predictions)
+    };
+    results.push(SolverBenchmark {
+        label: "SAT + AI warm-start".to_string(),
+        cells_filled: warm_start_solution.as_ref().map(count_filled).unwrap_or(0),
+        millis: start.elapsed().as_millis() as u64,
+    });
+
+    results
 }
 
 pub struct App {
@@ -17,14 +91,27 @@ pub struct App {
     pub puzzle_files: Vec<String>,
     pub current_grid: Option<Grid>,
     pub solved_grid: Option<Grid>,
-    pub solving_grid: Arc<Mutex<Option<Grid>>>,
+    pub solving_grid: Option<Grid>,
     pub solve_duration: Option<Duration>,
     pub solve_result: Option<String>,
     pub should_quit: bool,
-    pub steps_count: Arc<Mutex<usize>>,
+    pub steps_count: usize,
     pub solve_start_time: Option<Instant>,
-    pub current_message: Arc<Mutex<String>>,
-    pub solving_thread: Option<thread::JoinHandle<()>>,
+    pub current_message: String,
+    pub benchmark_results: Vec<SolverBenchmark>,
+    pub hint_session: Option<HintSession>,
+    pub hint_grid: Option<Grid>,
+    /// Cursor the player steers with the arrow keys while `Hinting`, to
+    /// place their own guesses for [`App::check_partial_solvable`] to
+    /// check rather than only pulling hints from the cached solution.
+    pub cursor: Point,
+    /// Colours the player can cycle through with [`App::cycle_fill_colour`]
+    /// when placing a cell, populated from the current puzzle's endpoints.
+    pub fill_colours: Vec<Colour>,
+    pub fill_colour_index: usize,
+    /// Warm-start model [`run_benchmark`] tries to load, so it isn't
+    /// hardcoded to one filename. Defaults to [`DEFAULT_MODEL_PATH`].
+    pub model_path: String,
 }
 
 impl App {
@@ -41,15 +128,167 @@ impl App {
             puzzle_files,
             current_grid: None,
             solved_grid: None,
-            solving_grid: Arc::new(Mutex::new(None)),
+            solving_grid: None,
             solve_duration: None,
             solve_result: None,
             should_quit: false,
-            steps_count: Arc::new(Mutex::new(0)),
+            steps_count: 0,
             solve_start_time: None,
-            current_message: Arc::new(Mutex::new(String::new())),
-            solving_thread: None,
+            current_message: String::new(),
+            benchmark_results: Vec::new(),
+            hint_session: None,
+            hint_grid: None,
+            cursor: Point::new(0, 0),
+            fill_colours: Vec::new(),
+            fill_colour_index: 0,
+            model_path: DEFAULT_MODEL_PATH.to_string(),
+        }
+    }
+
+    /// Solves `current_grid` once via [`HintSession`] and switches to the
+    /// `Hinting` screen so [`App::reveal_hint`] can pop cells one at a time.
+    /// If the puzzle has no solution to hint from, leaves `state` unchanged
+    /// and reports that in `current_message` instead.
+    pub fn start_hints(&mut self) {
+        let grid = match &self.current_grid {
+            Some(grid) => grid.clone(),
+            None => return,
+        };
+
+        match HintSession::new(&grid) {
+            Some(session) => {
+                self.fill_colours = grid.get_endpoints().keys().cloned().collect();
+                self.fill_colour_index = 0;
+                self.cursor = Point::new(0, 0);
+                self.hint_session = Some(session);
+                self.hint_grid = Some(grid);
+                self.state = AppState::Hinting;
+            }
+            None => {
+                self.current_message = "No solution to hint from".to_string();
+            }
+        }
+    }
+
+    /// Reveals one more cell's colour from the cached hint session onto
+    /// `hint_grid`, leaving the rest of the puzzle unspoiled.
+    pub fn reveal_hint(&mut self) {
+        let Some(session) = self.hint_session.as_mut() else {
+            return;
+        };
+        let Some((point, colour)) = session.next_hint() else {
+            return;
+        };
+        if let Some(grid) = self.hint_grid.as_mut() {
+            grid.set(point, Cell::Path { colour, solved: false });
+        }
+    }
+
+    /// Moves the fill-in cursor by `(dx, dy)`, clamped to stay on `hint_grid`.
+    pub fn move_cursor(&mut self, dx: isize, dy: isize) {
+        let Some(grid) = &self.hint_grid else {
+            return;
+        };
+        let x = (self.cursor.x as isize + dx).clamp(0, grid.width as isize - 1) as usize;
+        let y = (self.cursor.y as isize + dy).clamp(0, grid.height as isize - 1) as usize;
+        self.cursor = Point::new(x, y);
+    }
+
+    /// Cycles which colour [`App::place_at_cursor`] paints next.
+    pub fn cycle_fill_colour(&mut self) {
+        if self.fill_colours.is_empty() {
+            return;
+        }
+        self.fill_colour_index = (self.fill_colour_index + 1) % self.fill_colours.len();
+    }
+
+    /// Paints the currently selected colour onto the cursor's cell, if it's
+    /// empty. This is the player's own guess, as opposed to a pulled
+    /// [`App::reveal_hint`] — [`App::check_partial_solvable`] is what tells
+    /// them whether it still leads somewhere.
+    pub fn place_at_cursor(&mut self) {
+        let Some(&colour) = self.fill_colours.get(self.fill_colour_index) else {
+            return;
+        };
+        let Some(grid) = self.hint_grid.as_mut() else {
+            return;
+        };
+        if matches!(grid.get(self.cursor), Cell::Empty) {
+            grid.set(self.cursor, Cell::Path { colour, solved: false });
+        }
+    }
+
+    /// Clears the player's own guess back to empty. Never touches an
+    /// `Endpoint`, so the puzzle's fixed clues can't be erased by accident.
+    pub fn clear_at_cursor(&mut self) {
+        let Some(grid) = self.hint_grid.as_mut() else {
+            return;
+        };
+        if matches!(grid.get(self.cursor), Cell::Path { .. }) {
+            grid.set(self.cursor, Cell::Empty);
+        }
+    }
+
+    /// Checks the player's current placements on `hint_grid` against
+    /// [`crate::sat::is_partial_solvable`] and reports the verdict in
+    /// `current_message`, turning the hint screen into an assisted solver
+    /// rather than a one-shot oracle.
+    pub fn check_partial_solvable(&mut self) {
+        let Some(grid) = &self.hint_grid else {
+            return;
+        };
+        self.current_message = if crate::sat::is_partial_solvable(grid) {
+            "Still solvable".to_string()
+        } else {
+            "No longer solvable".to_string()
+        };
+    }
+
+    /// Switches to the `Solving` screen so the caller can hand `current_grid`
+    /// off to a background thread running `run_benchmark` (see
+    /// `tui::spawn_benchmark_thread`) instead of benchmarking inline on the
+    /// render thread.
+    pub fn start_benchmark(&mut self) {
+        if self.current_grid.is_some() {
+            self.state = AppState::Solving;
+        }
+    }
+
+    /// Applies a `BenchmarkDone` message from the background benchmark
+    /// thread, switching to the `Benchmark` screen so the results can be
+    /// compared side by side.
+    pub fn apply_benchmark_done(&mut self, results: Vec<SolverBenchmark>) {
+        self.benchmark_results = results;
+        self.state = AppState::Benchmark;
+    }
+
+    /// Switches to the `Solving` screen so the caller can hand `current_grid`
+    /// off to a background thread running `crate::astar::solve_parallel`
+    /// (see `tui::spawn_race_thread`) instead of racing the solvers inline
+    /// on the render thread.
+    pub fn start_race(&mut self) {
+        if self.current_grid.is_some() {
+            self.state = AppState::Solving;
+        }
+    }
+
+    /// Applies a `RaceDone` message from the background race thread: `None`
+    /// means every solver finished without producing a solved grid (e.g. an
+    /// unsolvable board), so that's reported instead of crediting a winner.
+    pub fn apply_race_done(&mut self, result: Option<crate::astar::ParallelSolveResult>) {
+        match result {
+            Some(result) => {
+                self.solved_grid = Some(result.grid);
+                self.solve_duration = Some(result.duration);
+                self.solve_result = Some(format!("Solved by {}", result.strategy));
+            }
+            None => {
+                self.solved_grid = None;
+                self.solve_duration = None;
+                self.solve_result = Some("No solver found a solution".to_string());
+            }
         }
+        self.state = AppState::ViewingSolution;
     }
 
     pub fn next_puzzle(&mut self) {
@@ -72,65 +311,58 @@ impl App {
         let path = PathBuf::from(format!("puzzles/{}", filename));
         self.current_grid = Some(grid_from_txt(path));
         self.solved_grid = None;
-        *self.solving_grid.lock().unwrap() = None;
+        self.solving_grid = None;
         self.solve_duration = None;
         self.solve_result = None;
-        *self.steps_count.lock().unwrap() = 0;
+        self.steps_count = 0;
         self.solve_start_time = None;
     }
 
     pub fn solve_puzzle(&mut self) {
         if let Some(grid) = &self.current_grid {
             self.state = AppState::Solving;
-            *self.solving_grid.lock().unwrap() = Some(grid.clone());
-            *self.steps_count.lock().unwrap() = 0;
+            self.solving_grid = Some(grid.clone());
+            self.steps_count = 0;
             self.solve_start_time = Some(Instant::now());
         }
     }
 
-    pub fn step_solve(&mut self) -> bool {
-        // This will be called repeatedly during solving
-        // Return true if solving is complete
-        let mut solving_grid_lock = self.solving_grid.lock().unwrap();
-        if let Some(solving_grid) = solving_grid_lock.as_mut() {
-            *self.steps_count.lock().unwrap() += 1;
-            
-            // Perform one step of the backtracking algorithm
-            // For now, we'll run the full solver and capture the result
-            let mut grid_copy = solving_grid.clone();
-            let solve_res = brute_force(&mut grid_copy);
-            
-            let is_complete = match solve_res {
-                SolveResult::Solved => true,
-                SolveResult::Impossible => true,
-            };
-
-            if is_complete {
-                self.solved_grid = Some(grid_copy);
-                self.solve_duration = self.solve_start_time.map(|start| start.elapsed());
-                self.solve_result = Some(match solve_res {
-                    SolveResult::Solved => "Solved!".to_string(),
-                    SolveResult::Impossible => "Impossible".to_string(),
-                });
-                self.state = AppState::ViewingSolution;
-                return true;
-            }
-            
-            false
-        } else {
-            true
-        }
+    /// Applies a `SolveProgress` message from the background solver thread:
+    /// latches the latest step count and grid snapshot so `render_solving`
+    /// can draw the solve as it animates.
+    pub fn apply_progress(&mut self, steps: usize, grid_snapshot: Grid) {
+        self.steps_count = steps;
+        self.solving_grid = Some(grid_snapshot);
+    }
+
+    /// Applies a terminal `SolveDone` message from the background solver
+    /// thread: the last progress snapshot becomes the solution, and the UI
+    /// moves on to `ViewingSolution`.
+    pub fn apply_done(&mut self, result: SolveResult) {
+        self.solved_grid = self.solving_grid.clone();
+        self.solve_duration = self.solve_start_time.map(|start| start.elapsed());
+        self.solve_result = Some(match result {
+            SolveResult::Solved => "Solved!".to_string(),
+            SolveResult::Impossible => "Impossible".to_string(),
+        });
+        self.state = AppState::ViewingSolution;
     }
 
     pub fn back_to_selection(&mut self) {
         self.state = AppState::PuzzleSelection;
         self.current_grid = None;
         self.solved_grid = None;
-        *self.solving_grid.lock().unwrap() = None;
+        self.solving_grid = None;
         self.solve_duration = None;
         self.solve_result = None;
-        *self.steps_count.lock().unwrap() = 0;
+        self.steps_count = 0;
         self.solve_start_time = None;
+        self.benchmark_results.clear();
+        self.hint_session = None;
+        self.hint_grid = None;
+        self.fill_colours.clear();
+        self.fill_colour_index = 0;
+        self.current_message.clear();
     }
 
     pub fn quit(&mut self) {
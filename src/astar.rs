@@ -1,6 +1,127 @@
 use crate::board::{Cell, Colour, Grid, Point};
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Bounds how long and how far `solve_astar_with` searches. A hard cap lets
+/// the TUI bail out of an unsatisfiable board instead of hanging;
+/// `max_solutions > 1` turns the search into an enumerator of distinct
+/// solved grids instead of stopping at the first.
+#[derive(Clone, Debug, Default)]
+pub struct SolverConfig {
+    pub timeout: Option<Duration>,
+    pub max_nodes: Option<usize>,
+    pub max_solutions: Option<usize>,
+    pub branch_heuristic: BranchHeuristic,
+    /// When set, [`solve_astar_with_tree`] records the expansion tree and
+    /// returns it alongside the solution instead of discarding it.
+    pub record_search_tree: bool,
+    /// Checked between node pops; when set to `true`, the search bails out
+    /// and returns the best partial fill seen so far. Used by
+    /// [`solve_parallel`] to stop losing strategies once another thread
+    /// has found a solution.
+    pub cancel: Option<Arc<AtomicBool>>,
+}
+
+/// Why a branch of the search tree stopped instead of being expanded
+/// further, recorded by [`solve_astar_with_tree`] next to the node it
+/// pruned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PruneReason {
+    DeadEnd,
+    Stranded,
+    /// This exact grid configuration was already explored via another path.
+    Visited,
+}
+
+/// A node in the tree of states `solve_astar_with_tree` explored: an
+/// optional value at this node (here, why the branch stopped, if it did)
+/// plus its children keyed by the move that produced them.
+#[derive(Clone, Debug)]
+pub struct SearchTree<K, V> {
+    pub value: Option<V>,
+    pub children: Vec<(K, SearchTree<K, V>)>,
+}
+
+impl<K, V> Default for SearchTree<K, V> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl<K: PartialEq + Clone, V> SearchTree<K, V> {
+    fn child_mut(&mut self, key: &K) -> &mut SearchTree<K, V> {
+        if let Some(pos) = self.children.iter().position(|(k, _)| k == key) {
+            &mut self.children[pos].1
+        } else {
+            self.children.push((key.clone(), SearchTree::default()));
+            let last = self.children.len() - 1;
+            &mut self.children[last].1
+        }
+    }
+
+    /// Walks (creating nodes as needed) the descendant reachable by
+    /// `path` from this root and sets its value.
+    fn set_value_at(&mut self, path: &[K], value: V) {
+        let mut node = self;
+        for key in path {
+            node = node.child_mut(key);
+        }
+        node.value = Some(value);
+    }
+}
+
+/// Candidate scoring strategies for which colour to advance next and which
+/// order to try its head's neighbour moves in. `Fewest` is the original
+/// most-constrained-variable rule; the rest trade it for orderings that
+/// suit different boards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BranchHeuristic {
+    /// Advance the colour whose path head has the fewest empty neighbours
+    /// (most-constrained-variable). The original, and still the default.
+    #[default]
+    Fewest,
+    /// Advance the colour whose head is closest to its goal endpoint.
+    Manhattan,
+    /// Advance the colour with the fewest combined free neighbours across
+    /// both its head and its goal endpoint.
+    MostConstrainedGoal,
+    /// Blend of `Manhattan` and `Fewest`: the geometric mean of the head's
+    /// goal distance and one more than its free-neighbour count.
+    Hybrid,
+}
+
+fn manhattan(a: Point, b: Point) -> usize {
+    a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
+}
+
+fn free_neighbour_count(grid: &Grid, p: Point) -> usize {
+    p.neighbors(grid.width, grid.height)
+        .into_iter()
+        .filter(|&n| matches!(grid.get(n), Cell::Empty))
+        .count()
+}
+
+/// Scores a path head under `branch_heuristic`: lower means more urgent to
+/// advance (for choosing the active colour) or more promising to try first
+/// (for ordering a colour's own successor moves).
+fn branch_score(branch_heuristic: BranchHeuristic, grid: &Grid, from: Point, goal: Point) -> f64 {
+    let free_moves = free_neighbour_count(grid, from) as f64;
+    match branch_heuristic {
+        BranchHeuristic::Fewest => free_moves,
+        BranchHeuristic::Manhattan => manhattan(from, goal) as f64,
+        BranchHeuristic::MostConstrainedGoal => {
+            free_moves + free_neighbour_count(grid, goal) as f64
+        }
+        BranchHeuristic::Hybrid => (manhattan(from, goal) as f64 * (free_moves + 1.0)).sqrt(),
+    }
+}
 
 #[derive(Clone, Eq, PartialEq)]
 struct State {
@@ -207,10 +328,9 @@ fn get_active_colour(
     grid: &Grid,
     endpoints: &HashMap<Colour, (Point, Point)>,
     paths: &HashMap<Colour, Vec<Point>>,
+    branch_heuristic: BranchHeuristic,
 ) -> Option<Colour> {
-    let width = grid.width;
-    let height = grid.height;
-    let mut min_moves = usize::MAX;
+    let mut best_score = f64::INFINITY;
     let mut active: Option<Colour> = None;
     for (&colour, path) in paths {
         let &last = path.last().unwrap();
@@ -218,20 +338,237 @@ fn get_active_colour(
         if last == end {
             continue;
         }
-        let moves = last
-            .neighbors(width, height)
-            .into_iter()
-            .filter(|&n| matches!(grid.get(n), Cell::Empty))
-            .count();
-        if moves < min_moves {
-            min_moves = moves;
+        let score = branch_score(branch_heuristic, grid, last, end);
+        if score < best_score {
+            best_score = score;
             active = Some(colour);
         }
     }
     active
 }
 
+/// Repeatedly applies forced moves (cells with only one legal colour) until
+/// a fixpoint, then prunes the state if collapsing left it a dead end or
+/// stranded — checked *after* the collapse loop so a contradiction the
+/// forced moves themselves introduce is caught, not just one the state
+/// already had on entry. A state the collapse left solved is returned as
+/// is, without these checks. Shared by `solve_beam`, `solve_astar_with`, and
+/// `solve_astar_with_tree` so all three prune on the same rule instead of
+/// drifting copies of this loop.
+fn collapse_forced_moves(mut state: State) -> Result<State, PruneReason> {
+    loop {
+        if let Some((colour, forced_move)) =
+            get_forced_moves(&state.grid, &state.endpoints, &state.paths)
+        {
+            let mut new_grid = state.grid.clone();
+            new_grid.set(forced_move, Cell::Path { colour, solved: false });
+            let mut new_paths = state.paths.clone();
+            let mut new_path = new_paths.get(&colour).unwrap().clone();
+            new_path.push(forced_move);
+            new_paths.insert(colour, new_path);
+            state = State {
+                grid: new_grid.clone(),
+                endpoints: state.endpoints.clone(),
+                paths: new_paths,
+                cost: state.cost,
+                estimate: heuristic(&new_grid),
+            };
+        } else {
+            break;
+        }
+    }
+
+    if state.grid.is_solved(&state.endpoints) {
+        Ok(state)
+    } else if dead_end(&state.grid) {
+        Err(PruneReason::DeadEnd)
+    } else if stranded(&state.grid, &state.endpoints, &state.paths) {
+        Err(PruneReason::Stranded)
+    } else {
+        Ok(state)
+    }
+}
+
+/// Expands one state's active colour into its successor states, exactly as
+/// the inner expansion block of `solve_astar` does for the `open` heap.
+/// `branch_heuristic` picks the active colour and the order its neighbour
+/// moves are tried in (see [`BranchHeuristic`]).
+fn expand_successors(state: State, branch_heuristic: BranchHeuristic) -> Vec<State> {
+    expand_successors_labeled(state, branch_heuristic)
+        .into_iter()
+        .map(|(_key, successor)| successor)
+        .collect()
+}
+
+/// Same expansion as [`expand_successors`], but keeps each successor
+/// paired with the `(Colour, Point)` move that produced it, so a caller
+/// building a [`SearchTree`] can label the edge to that child.
+fn expand_successors_labeled(
+    state: State,
+    branch_heuristic: BranchHeuristic,
+) -> Vec<((Colour, Point), State)> {
+    let mut successors = Vec::new();
+    let active_colour = match get_active_colour(
+        &state.grid,
+        &state.endpoints,
+        &state.paths,
+        branch_heuristic,
+    ) {
+        Some(colour) => colour,
+        None => return successors,
+    };
+    let path = state.paths.get(&active_colour).unwrap();
+    let &last = path.last().unwrap();
+    let (_start, end) = state.endpoints[&active_colour];
+    if last == end {
+        return successors;
+    }
+
+    let mut neighbors = last.neighbors(state.grid.width, state.grid.height);
+    neighbors.sort_by(|&a, &b| {
+        branch_score(branch_heuristic, &state.grid, a, end)
+            .partial_cmp(&branch_score(branch_heuristic, &state.grid, b, end))
+            .unwrap_or(Ordering::Equal)
+    });
+
+    for neighbor in neighbors {
+        match state.grid.get(neighbor) {
+            Cell::Empty => {
+                let mut new_grid = state.grid.clone();
+                new_grid.set(neighbor, Cell::Path { colour: active_colour, solved: false });
+                let mut new_paths = state.paths.clone();
+                let mut new_path = path.clone();
+                new_path.push(neighbor);
+                new_paths.insert(active_colour, new_path);
+                let cost = state.cost + 1;
+                let estimate = cost + heuristic(&new_grid);
+                successors.push((
+                    (active_colour, neighbor),
+                    State {
+                        grid: new_grid,
+                        endpoints: state.endpoints.clone(),
+                        paths: new_paths,
+                        cost,
+                        estimate,
+                    },
+                ));
+            }
+            Cell::Endpoint { colour: c, .. } if c == active_colour => {
+                let new_grid = state.grid.clone();
+                let mut new_paths = state.paths.clone();
+                let mut new_path = path.clone();
+                new_path.push(neighbor);
+                new_paths.insert(active_colour, new_path);
+                let cost = state.cost + 1;
+                let estimate = cost + heuristic(&new_grid);
+                successors.push((
+                    (active_colour, neighbor),
+                    State {
+                        grid: new_grid,
+                        endpoints: state.endpoints.clone(),
+                        paths: new_paths,
+                        cost,
+                        estimate,
+                    },
+                ));
+            }
+            _ => {}
+        }
+    }
+    successors
+}
+
+/// Beam-limited, level-synchronous variant of `solve_astar` that bounds
+/// memory on large boards: instead of an unbounded `BinaryHeap`, only the
+/// best `beam_width` states survive each generation (`Vec<State>` holds the
+/// whole frontier, expanded together and truncated by `estimate`). Beam
+/// search is incomplete, so an outer restart loop doubles the beam width up
+/// to `max_restarts` times before giving up, returning the best partial
+/// fill seen along the way so the caller always has something to show.
+pub fn solve_beam(
+    grid: &Grid,
+    beam_width: usize,
+    max_restarts: usize,
+    branch_heuristic: BranchHeuristic,
+    cancel: Option<Arc<AtomicBool>>,
+) -> Grid {
+    let endpoints = grid.get_endpoints();
+    let mut initial_paths = HashMap::new();
+    for (&colour, &(start, _end)) in &endpoints {
+        initial_paths.insert(colour, vec![start]);
+    }
+    let initial_state = State {
+        grid: grid.clone(),
+        endpoints,
+        paths: initial_paths,
+        cost: 0,
+        estimate: heuristic(grid),
+    };
+
+    let mut best_partial = initial_state.grid.clone();
+    let mut best_estimate = initial_state.estimate;
+    let mut width = beam_width.max(1);
+    let cancelled = || cancel.as_ref().is_some_and(|flag| flag.load(AtomicOrdering::Relaxed));
+
+    for _ in 0..=max_restarts {
+        if cancelled() {
+            break;
+        }
+        let mut frontier = vec![initial_state.clone()];
+
+        while !frontier.is_empty() {
+            if cancelled() {
+                return best_partial;
+            }
+            let mut successors = Vec::new();
+
+            for state in frontier {
+                let state = match collapse_forced_moves(state) {
+                    Ok(state) => state,
+                    Err(_) => continue,
+                };
+
+                if state.estimate < best_estimate {
+                    best_estimate = state.estimate;
+                    best_partial = state.grid.clone();
+                }
+                if state.grid.is_solved(&state.endpoints) {
+                    return state.grid;
+                }
+
+                successors.extend(expand_successors(state, branch_heuristic));
+            }
+
+            successors.sort_by_key(|s| s.estimate);
+            successors.truncate(width);
+            frontier = successors;
+        }
+
+        width = width.saturating_mul(2);
+    }
+
+    best_partial
+}
+
+/// Exact A* search, unbounded. Delegates to [`solve_astar_with`] with a
+/// default [`SolverConfig`] (no timeout, no node cap, a single solution),
+/// so existing callers keep their old signature and behaviour.
 pub fn solve_astar(grid: Grid) -> Option<Grid> {
+    let endpoints = grid.get_endpoints();
+    solve_astar_with(&grid, &SolverConfig::default())
+        .into_iter()
+        .next()
+        .filter(|solved| solved.is_solved(&endpoints))
+}
+
+/// A* search bounded by `config`'s wall-clock, node-count, and
+/// solution-count limits. When the budget runs out before any solution is
+/// found, returns the single best partial fill seen so far instead of an
+/// empty result, so the TUI always has something to show instead of
+/// hanging on an unsatisfiable board. When `max_solutions > 1`, keeps
+/// searching past the first solved grid and collects up to that many
+/// distinct solutions.
+pub fn solve_astar_with(grid: &Grid, config: &SolverConfig) -> Vec<Grid> {
     let endpoints = grid.get_endpoints();
     let mut initial_paths = HashMap::new();
     for (&colour, &(start, _end)) in &endpoints {
@@ -242,102 +579,289 @@ pub fn solve_astar(grid: Grid) -> Option<Grid> {
         endpoints: endpoints.clone(),
         paths: initial_paths,
         cost: 0,
-        estimate: heuristic(&grid),
+        estimate: heuristic(grid),
     };
     let mut open = BinaryHeap::new();
     open.push(initial_state);
     let mut visited = HashSet::new();
-    while let Some(mut state) = open.pop() {
+
+    let start_time = Instant::now();
+    let mut nodes = 0usize;
+    let mut best_partial = grid.clone();
+    let mut best_estimate = heuristic(grid);
+    let max_solutions = config.max_solutions.unwrap_or(1).max(1);
+    let mut solutions = Vec::new();
+
+    while let Some(state) = open.pop() {
+        if config
+            .timeout
+            .is_some_and(|timeout| start_time.elapsed() >= timeout)
+        {
+            break;
+        }
+        if config.max_nodes.is_some_and(|max_nodes| nodes >= max_nodes) {
+            break;
+        }
+        if config
+            .cancel
+            .as_ref()
+            .is_some_and(|flag| flag.load(AtomicOrdering::Relaxed))
+        {
+            break;
+        }
+        nodes += 1;
+
         let grid_hash = state.grid.cells.iter().flatten().fold(0u64, |acc, c| {
             acc.wrapping_mul(31).wrapping_add(match c {
                 Cell::Empty => 0,
-                Cell::Endpoint { colour } => 1 + (*colour as u64),
-                Cell::Path { colour } => 100 + (*colour as u64),
+                Cell::Endpoint { colour, .. } => 1 + (*colour as u64),
+                Cell::Path { colour, .. } => 100 + (*colour as u64),
             })
         });
         if visited.contains(&grid_hash) {
             continue;
         }
         visited.insert(grid_hash);
-        if dead_end(&state.grid) || stranded(&state.grid, &state.endpoints, &state.paths) {
-            continue;
+        let state = match collapse_forced_moves(state) {
+            Ok(state) => state,
+            Err(_) => continue,
+        };
+
+        if state.estimate < best_estimate {
+            best_estimate = state.estimate;
+            best_partial = state.grid.clone();
         }
-        loop {
-            if let Some((colour, forced_move)) =
-                get_forced_moves(&state.grid, &state.endpoints, &state.paths)
-            {
-                let mut new_grid = state.grid.clone();
-                new_grid.set(forced_move, Cell::Path { colour });
-                let mut new_paths = state.paths.clone();
-                let mut new_path = new_paths.get(&colour).unwrap().clone();
-                new_path.push(forced_move);
-                new_paths.insert(colour, new_path);
-                state = State {
-                    grid: new_grid.clone(),
-                    endpoints: state.endpoints.clone(),
-                    paths: new_paths,
-                    cost: state.cost,
-                    estimate: heuristic(&new_grid),
-                };
-            } else {
-                break;
+
+        if state.grid.is_solved(&state.endpoints) {
+            if !solutions.contains(&state.grid) {
+                solutions.push(state.grid.clone());
+            }
+            if solutions.len() >= max_solutions {
+                return solutions;
             }
+            continue;
         }
-        if state.grid.is_solved(&state.endpoints) {
-            return Some(state.grid);
+        for successor in expand_successors(state, config.branch_heuristic) {
+            open.push(successor);
         }
-        if let Some(active_colour) = get_active_colour(&state.grid, &state.endpoints, &state.paths)
+    }
+
+    if solutions.is_empty() {
+        vec![best_partial]
+    } else {
+        solutions
+    }
+}
+
+/// Same search as [`solve_astar_with`], but additionally records the
+/// expansion tree: every successor pushed from the active colour's
+/// expansion block registers an edge keyed by `(Colour, Point)` under its
+/// parent, and a node that gets pruned (dead end, stranded, or already
+/// visited) is annotated with the [`PruneReason`] that stopped it. The
+/// tree is only built, and only returned, when `config.record_search_tree`
+/// is set — callers that don't need it should use the cheaper
+/// `solve_astar_with` instead.
+pub fn solve_astar_with_tree(
+    grid: &Grid,
+    config: &SolverConfig,
+) -> (Vec<Grid>, Option<SearchTree<(Colour, Point), PruneReason>>) {
+    let endpoints = grid.get_endpoints();
+    let mut initial_paths = HashMap::new();
+    for (&colour, &(start, _end)) in &endpoints {
+        initial_paths.insert(colour, vec![start]);
+    }
+    let initial_state = State {
+        grid: grid.clone(),
+        endpoints: endpoints.clone(),
+        paths: initial_paths,
+        cost: 0,
+        estimate: heuristic(grid),
+    };
+
+    struct QueuedState {
+        state: State,
+        path: Vec<(Colour, Point)>,
+    }
+    impl PartialEq for QueuedState {
+        fn eq(&self, other: &Self) -> bool {
+            self.state == other.state
+        }
+    }
+    impl Eq for QueuedState {}
+    impl Ord for QueuedState {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.state.cmp(&other.state)
+        }
+    }
+    impl PartialOrd for QueuedState {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    let mut open = BinaryHeap::new();
+    open.push(QueuedState {
+        state: initial_state,
+        path: Vec::new(),
+    });
+    let mut visited = HashSet::new();
+
+    let start_time = Instant::now();
+    let mut nodes = 0usize;
+    let mut best_partial = grid.clone();
+    let mut best_estimate = heuristic(grid);
+    let max_solutions = config.max_solutions.unwrap_or(1).max(1);
+    let mut solutions = Vec::new();
+    let mut tree = SearchTree::default();
+
+    while let Some(QueuedState { state, path }) = open.pop() {
+        if config
+            .timeout
+            .is_some_and(|timeout| start_time.elapsed() >= timeout)
         {
-            let path = state.paths.get(&active_colour).unwrap();
-            let &last = path.last().unwrap();
-            let (_start, end) = state.endpoints[&active_colour];
-            if last == end {
-                continue;
+            break;
+        }
+        if config.max_nodes.is_some_and(|max_nodes| nodes >= max_nodes) {
+            break;
+        }
+        if config
+            .cancel
+            .as_ref()
+            .is_some_and(|flag| flag.load(AtomicOrdering::Relaxed))
+        {
+            break;
+        }
+        nodes += 1;
+
+        let grid_hash = state.grid.cells.iter().flatten().fold(0u64, |acc, c| {
+            acc.wrapping_mul(31).wrapping_add(match c {
+                Cell::Empty => 0,
+                Cell::Endpoint { colour, .. } => 1 + (*colour as u64),
+                Cell::Path { colour, .. } => 100 + (*colour as u64),
+            })
+        });
+        if visited.contains(&grid_hash) {
+            if config.record_search_tree {
+                tree.set_value_at(&path, PruneReason::Visited);
             }
-            for neighbor in last.neighbors(state.grid.width, state.grid.height) {
-                match state.grid.get(neighbor) {
-                    Cell::Empty => {
-                        let mut new_grid = state.grid.clone();
-                        new_grid.set(
-                            neighbor,
-                            Cell::Path {
-                                colour: active_colour,
-                            },
-                        );
-                        let mut new_paths = state.paths.clone();
-                        let mut new_path = path.clone();
-                        new_path.push(neighbor);
-                        new_paths.insert(active_colour, new_path);
-                        let cost = state.cost + 1;
-                        let estimate = cost + heuristic(&new_grid);
-                        open.push(State {
-                            grid: new_grid,
-                            endpoints: state.endpoints.clone(),
-                            paths: new_paths,
-                            cost,
-                            estimate,
-                        });
-                    }
-                    Cell::Endpoint { colour: c } if c == active_colour => {
-                        let new_grid = state.grid.clone();
-                        let mut new_paths = state.paths.clone();
-                        let mut new_path = path.clone();
-                        new_path.push(neighbor);
-                        new_paths.insert(active_colour, new_path);
-                        let cost = state.cost + 1;
-                        let estimate = cost + heuristic(&new_grid);
-                        open.push(State {
-                            grid: new_grid,
-                            endpoints: state.endpoints.clone(),
-                            paths: new_paths,
-                            cost,
-                            estimate,
-                        });
-                    }
-                    _ => {}
+            continue;
+        }
+        visited.insert(grid_hash);
+        let state = match collapse_forced_moves(state) {
+            Ok(state) => state,
+            Err(reason) => {
+                if config.record_search_tree {
+                    tree.set_value_at(&path, reason);
                 }
+                continue;
             }
+        };
+
+        if state.estimate < best_estimate {
+            best_estimate = state.estimate;
+            best_partial = state.grid.clone();
+        }
+
+        if state.grid.is_solved(&state.endpoints) {
+            if !solutions.contains(&state.grid) {
+                solutions.push(state.grid.clone());
+            }
+            if solutions.len() >= max_solutions {
+                return (solutions, config.record_search_tree.then_some(tree));
+            }
+            continue;
+        }
+        for (key, successor) in expand_successors_labeled(state, config.branch_heuristic) {
+            let mut child_path = path.clone();
+            child_path.push(key);
+            open.push(QueuedState {
+                state: successor,
+                path: child_path,
+            });
         }
     }
-    None
+
+    let solutions = if solutions.is_empty() {
+        vec![best_partial]
+    } else {
+        solutions
+    };
+    (solutions, config.record_search_tree.then_some(tree))
+}
+
+/// Result of racing every solving strategy against the same board in
+/// [`solve_parallel`]: the winning solved grid, that strategy's display
+/// name, and how long the race took wall-clock.
+pub struct ParallelSolveResult {
+    pub grid: Grid,
+    pub strategy: &'static str,
+    pub duration: Duration,
+}
+
+/// Launches `solve_astar_with`, `solve_beam`, and `brute_force_with_cancel`
+/// on their own threads and returns whichever produces a solved grid first.
+/// All three poll the same shared `AtomicBool` between steps (see
+/// `SolverConfig::cancel`, `solve_beam`'s `cancel` parameter, and
+/// `backtracking::brute_force_with_cancel`) and bail out once another thread
+/// wins, so the race's wall-clock is never held hostage by whichever
+/// strategy is slowest to finish on its own. Returns `None` if all three
+/// solvers finish without ever sending a solved grid (e.g. an unsolvable
+/// board), rather than assuming one of them always wins.
+pub fn solve_parallel(grid: &Grid) -> Option<ParallelSolveResult> {
+    let cancel = Arc::new(AtomicBool::new(false));
+    let (tx, rx) = mpsc::channel();
+    let start = Instant::now();
+
+    {
+        let grid = grid.clone();
+        let cancel = cancel.clone();
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let config = SolverConfig {
+                cancel: Some(cancel),
+                ..SolverConfig::default()
+            };
+            if let Some(solved) = solve_astar_with(&grid, &config)
+                .into_iter()
+                .find(|g| g.is_solved(&g.get_endpoints()))
+            {
+                let _ = tx.send(("A*", solved));
+            }
+        });
+    }
+    {
+        let grid = grid.clone();
+        let cancel = cancel.clone();
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let solved = solve_beam(&grid, 64, 4, BranchHeuristic::default(), Some(cancel));
+            if solved.is_solved(&solved.get_endpoints()) {
+                let _ = tx.send(("Beam", solved));
+            }
+        });
+    }
+    {
+        let grid = grid.clone();
+        let cancel = cancel.clone();
+        thread::spawn(move || {
+            let mut grid = grid;
+            let result = crate::backtracking::brute_force_with_cancel(
+                &mut grid,
+                &mut |_steps, _grid| {},
+                Some(cancel),
+            );
+            if result == crate::backtracking::SolveResult::Solved {
+                let _ = tx.send(("Backtracking", grid));
+            }
+        });
+    }
+
+    let (strategy, solved_grid) = rx.recv().ok()?;
+    cancel.store(true, AtomicOrdering::Relaxed);
+
+    Some(ParallelSolveResult {
+        grid: solved_grid,
+        strategy,
+        duration: start.elapsed(),
+    })
 }
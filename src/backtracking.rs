@@ -1,5 +1,13 @@
 use crate::*;
 use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveResult {
+    Solved,
+    Impossible,
+}
 
 fn find_paths(
     grid: &Grid,
@@ -28,13 +36,13 @@ fn find_paths(
                 results.extend(subpaths);
                 path.pop();
             }
-            Cell::Path { colour: c } if c == colour => {
+            Cell::Path { colour: c, .. } if c == colour => {
                 path.push(neighbour);
                 let subpaths = find_paths(grid, neighbour, end, colour, visited, path);
                 results.extend(subpaths);
                 path.pop();
             }
-            Cell::Endpoint { colour: c } if c == colour => {
+            Cell::Endpoint { colour: c, .. } if c == colour => {
                 path.push(neighbour);
                 let subpaths = find_paths(grid, neighbour, end, colour, visited, path);
                 results.extend(subpaths);
@@ -48,19 +56,54 @@ fn find_paths(
     results
 }
 
-pub fn brute_force(grid: &mut Grid) -> bool {
+pub fn brute_force(grid: &mut Grid) -> SolveResult {
+    brute_force_with_progress(grid, &mut |_steps, _grid| {})
+}
+
+/// Same search as [`brute_force`], but calls `progress(steps, grid)` once
+/// before the search (with `steps == 0`) and after every path placement it
+/// tries, so a caller streaming these snapshots through a channel can
+/// animate the solve instead of only seeing the start and end state.
+pub fn brute_force_with_progress(
+    grid: &mut Grid,
+    progress: &mut impl FnMut(usize, &Grid),
+) -> SolveResult {
+    brute_force_with_cancel(grid, progress, None)
+}
+
+/// Same search as [`brute_force_with_progress`], but bails out early (as
+/// [`SolveResult::Impossible`]) once `cancel` is set, so `astar::solve_parallel`
+/// can race this against A* and beam without it running to completion after
+/// losing.
+pub fn brute_force_with_cancel(
+    grid: &mut Grid,
+    progress: &mut impl FnMut(usize, &Grid),
+    cancel: Option<Arc<AtomicBool>>,
+) -> SolveResult {
     let endpoints = grid.get_endpoints();
     grid.fill_guaranteed(&endpoints);
 
     let pairs: Vec<(Colour, Point, Point)> =
         endpoints.iter().map(|(&c, &(s, e))| (c, s, e)).collect();
 
+    let mut steps = 0usize;
+    progress(steps, grid);
+
+    let cancelled = || cancel.as_ref().is_some_and(|flag| flag.load(Ordering::Relaxed));
+
     fn backtrack(
         grid: &mut Grid,
         pairs: &[(Colour, Point, Point)],
         index: usize,
         endpoints: &HashMap<Colour, (Point, Point)>,
+        steps: &mut usize,
+        progress: &mut impl FnMut(usize, &Grid),
+        cancelled: &impl Fn() -> bool,
     ) -> bool {
+        if cancelled() {
+            return false;
+        }
+
         if index == pairs.len() {
             return grid.is_solved(endpoints);
         }
@@ -68,7 +111,7 @@ pub fn brute_force(grid: &mut Grid) -> bool {
         let (colour, start, end) = pairs[index];
 
         if grid.connected(colour, start, end) {
-            return backtrack(grid, pairs, index + 1, endpoints);
+            return backtrack(grid, pairs, index + 1, endpoints, steps, progress, cancelled);
         }
 
         let mut visited = HashSet::new();
@@ -77,20 +120,26 @@ pub fn brute_force(grid: &mut Grid) -> bool {
         let all_paths = find_paths(grid, start, end, colour, &mut visited, &mut path);
 
         for path in all_paths.iter() {
+            if cancelled() {
+                return false;
+            }
+
             for &p in path {
                 if matches!(grid.get(p), Cell::Empty) {
-                    grid.set(p, Cell::Path { colour });
+                    grid.set(p, Cell::Path { colour, solved: false });
                 }
             }
 
             grid.fill_guaranteed(endpoints);
+            *steps += 1;
+            progress(*steps, grid);
 
-            if backtrack(grid, pairs, index + 1, endpoints) {
+            if backtrack(grid, pairs, index + 1, endpoints, steps, progress, cancelled) {
                 return true;
             }
 
             for &p in path {
-                if let Cell::Path { colour: c } = grid.get(p) {
+                if let Cell::Path { colour: c, .. } = grid.get(p) {
                     if c == colour {
                         grid.set(p, Cell::Empty);
                     }
@@ -101,5 +150,9 @@ pub fn brute_force(grid: &mut Grid) -> bool {
         false
     }
 
-    backtrack(grid, &pairs, 0, &endpoints)
+    if backtrack(grid, &pairs, 0, &endpoints, &mut steps, progress, &cancelled) {
+        SolveResult::Solved
+    } else {
+        SolveResult::Impossible
+    }
 }
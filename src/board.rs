@@ -140,7 +140,7 @@ impl Point {
     }
 }
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Grid {
     pub width: usize,
     pub height: usize,
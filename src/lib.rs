@@ -1,5 +1,10 @@
+pub mod app;
+pub mod astar;
 pub mod backtracking;
 pub mod board;
+pub mod puzzle_ai;
+pub mod sat;
+pub mod tui;
 pub mod utils;
 pub mod solver;
 pub use board::{Cell, Colour, Grid, Point};
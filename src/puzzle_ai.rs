@@ -1,44 +1,101 @@
-use crate::board::Grid;
-use crate::backtracking::SolveResult;
-use lazy_static::lazy_static;
+use crate::board::{Cell, Colour, Grid, Point};
+use std::collections::HashMap;
+use std::path::Path;
 use tch::{CModule, Device, Kind, Tensor};
 
-lazy_static! {
-    static ref MODEL: CModule =
-        CModule::load("flowai/models/sudoku_ts.pt").expect("could not load TorchScript model");
+/// Produces a per-cell colour confidence map for a grid, so a solver can
+/// use a prediction as a warm start instead of hard-coding one model or
+/// encoding. `predict` is only asked about empty cells; endpoints are
+/// already known.
+pub trait ModelBackend {
+    fn predict(&self, grid: &Grid, colours: &[Colour]) -> HashMap<Point, (Colour, f32)>;
 }
 
-pub fn ai_solver(grid: &mut Grid) -> SolveResult {
-    // Flatten grid values (0..=9) into f32
-    let input_data: Vec<f32> = grid
-        .cells
-        .iter()
-        .map(|c| c.value as f32)
-        .collect();
-
-    // Build a [1,1,N,N] tensor
-    let size = grid.size as i64;
-    let tensor = Tensor::of_slice(&input_data)
-        .view([1, 1, size, size])
-        .to_device(Device::Cpu);
-
-    // Forward pass
-    let output = MODEL
-        .forward_ts(&[tensor])
-        .expect("model forward failed");
-    // output shape: [1, N*N, max_val], pick argmax per cell
-    let preds = output
-        .softmax(-1, Kind::Float)
-        .argmax(-1, false)
-        .view([-1])
-        .into::<Vec<i64>>();
-
-    // Fill grid
-    for (i, &p) in preds.iter().enumerate() {
-        let row = i / grid.size;
-        let col = i % grid.size;
-        grid.set(row, col, (p as u8) + 1);
+/// A [`ModelBackend`] backed by a TorchScript module loaded from disk. The
+/// grid is encoded as one `[H, W]` channel per colour, 1 at that colour's
+/// two endpoints and 0 elsewhere, stacked into a `[1, C, H, W]` tensor —
+/// the model only ever sees endpoint placement, never a pre-existing path.
+pub struct TorchModelBackend {
+    module: CModule,
+}
+
+impl TorchModelBackend {
+    /// Loads the TorchScript module at `path`. Returns `None` (instead of
+    /// panicking) if it can't be loaded, so callers can fall back to pure
+    /// SAT when no model is present.
+    pub fn load(path: impl AsRef<Path>) -> Option<Self> {
+        CModule::load(path).ok().map(|module| Self { module })
     }
 
-    SolveResult::Solved
-}
\ No newline at end of file
+    fn encode(grid: &Grid, colours: &[Colour]) -> Tensor {
+        let width = grid.width;
+        let height = grid.height;
+        let mut input = vec![0f32; colours.len() * width * height];
+
+        for y in 0..height {
+            for x in 0..width {
+                if let Cell::Endpoint { colour, .. } = grid.get(Point::new(x, y)) {
+                    if let Some(channel) = colours.iter().position(|&c| c == colour) {
+                        input[channel * width * height + y * width + x] = 1.0;
+                    }
+                }
+            }
+        }
+
+        Tensor::of_slice(&input)
+            .view([1, colours.len() as i64, height as i64, width as i64])
+            .to_device(Device::Cpu)
+    }
+}
+
+impl ModelBackend for TorchModelBackend {
+    fn predict(&self, grid: &Grid, colours: &[Colour]) -> HashMap<Point, (Colour, f32)> {
+        let tensor = Self::encode(grid, colours);
+
+        let output = match self.module.forward_ts(&[tensor]) {
+            Ok(output) => output,
+            Err(_) => return HashMap::new(),
+        };
+        // output shape: [1, H*W, num_colours] -> per-cell colour distribution
+        let probs = output.softmax(-1, Kind::Float);
+
+        let mut predictions = HashMap::new();
+        for y in 0..grid.height {
+            for x in 0..grid.width {
+                let point = Point::new(x, y);
+                if !matches!(grid.get(point), Cell::Empty) {
+                    continue;
+                }
+                let cell_idx = (y * grid.width + x) as i64;
+                let mut best: Option<(Colour, f32)> = None;
+                for (channel, &colour) in colours.iter().enumerate() {
+                    let confidence = probs.double_value(&[0, cell_idx, channel as i64]) as f32;
+                    if best.is_none_or(|(_, best_confidence)| confidence > best_confidence) {
+                        best = Some((colour, confidence));
+                    }
+                }
+                if let Some(prediction) = best {
+                    predictions.insert(point, prediction);
+                }
+            }
+        }
+        predictions
+    }
+}
+
+/// Filters a backend's predictions down to the ones confident enough to
+/// hand to the solver as a warm start (see
+/// [`crate::sat::solve_sat_with_warm_start`]).
+pub fn warm_start_predictions(
+    grid: &Grid,
+    backend: &dyn ModelBackend,
+    colours: &[Colour],
+    threshold: f32,
+) -> Vec<(Point, Colour)> {
+    backend
+        .predict(grid, colours)
+        .into_iter()
+        .filter(|&(_, (_, confidence))| confidence >= threshold)
+        .map(|(point, (colour, _))| (point, colour))
+        .collect()
+}
@@ -12,24 +12,140 @@ const DIR_TYPES: [u8; 6] = [
 ];
 
 use crate::board::{Cell, Colour, Grid, Point};
-use std::collections::HashMap;
-use varisat::{CnfFormula, ExtendFormula, Solver};
+use std::collections::{HashMap, HashSet};
+use varisat::{CnfFormula, ExtendFormula, Lit, Solver};
 
-pub fn solve_sat(grid: &Grid) -> Option<Grid> {
+type Domain = HashMap<Point, HashSet<Colour>>;
+
+/// Runs a constraint-propagation fixpoint over the candidate colours of
+/// every cell before the CNF is built: fix forced cells and prune impossible
+/// colours so the formula the SAT solver has to search is smaller. Returns
+/// `None` as soon as a cell's candidate set goes empty, meaning the puzzle
+/// has no solution.
+fn propagate_candidates(
+    grid: &Grid,
+    endpoints: &HashMap<Colour, (Point, Point)>,
+    colours: &[Colour],
+) -> Option<Domain> {
+    let width = grid.width;
+    let height = grid.height;
+    let mut domain: Domain = HashMap::new();
+
+    for y in 0..height {
+        for x in 0..width {
+            let point = Point::new(x, y);
+            let set = match grid.get(point) {
+                Cell::Endpoint { colour, .. } => {
+                    let mut s = HashSet::new();
+                    s.insert(colour);
+                    s
+                }
+                _ => colours.iter().cloned().collect(),
+            };
+            domain.insert(point, set);
+        }
+    }
+
+    loop {
+        let mut changed = false;
+
+        // A colour only survives at a cell if at least one neighbor can
+        // still carry it: every occupied cell needs a same-coloured
+        // neighbor to start, continue, or end its path.
+        for y in 0..height {
+            for x in 0..width {
+                let point = Point::new(x, y);
+                if matches!(grid.get(point), Cell::Endpoint { .. }) {
+                    continue;
+                }
+                let neighbor_colours: HashSet<Colour> = point
+                    .neighbors(width, height)
+                    .iter()
+                    .flat_map(|n| domain[n].iter().cloned())
+                    .collect();
+                let set = domain.get_mut(&point).unwrap();
+                let before = set.len();
+                set.retain(|c| neighbor_colours.contains(c));
+                if set.is_empty() {
+                    return None;
+                }
+                if set.len() != before {
+                    changed = true;
+                }
+            }
+        }
+
+        // An endpoint has exactly one neighbor of its colour. If every
+        // neighbor but one has already lost that colour as a candidate
+        // (trivially true in a corner, where there are only two to begin
+        // with), the remaining neighbor is forced to it.
+        for (&colour, &(p1, p2)) in endpoints {
+            for &endpoint in &[p1, p2] {
+                let forced: Vec<Point> = endpoint
+                    .neighbors(width, height)
+                    .into_iter()
+                    .filter(|n| domain[n].contains(&colour))
+                    .collect();
+                if let [only] = forced[..] {
+                    let set = domain.get_mut(&only).unwrap();
+                    if set.len() != 1 {
+                        set.clear();
+                        set.insert(colour);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    Some(domain)
+}
+
+/// Outcome of checking a puzzle for a unique solution via [`solve_sat_unique`].
+#[derive(Debug)]
+pub enum SolveOutcome {
+    /// Exactly one solution exists; it is attached.
+    Unique(Grid),
+    /// At least two distinct solutions exist.
+    Multiple,
+    /// No solution exists.
+    Unsolvable,
+}
+
+/// The CNF encoding shared by [`solve_sat`] and [`solve_sat_unique`]: the
+/// cell-color variables (keyed the same way callers already index `var_map`)
+/// plus the formula built from them.
+struct Encoding {
+    formula: CnfFormula,
+    var_map: HashMap<(usize, usize, Colour), i32>,
+    colours: Vec<Colour>,
+}
+
+fn build_formula(grid: &Grid) -> Option<Encoding> {
     let endpoints = grid.get_endpoints();
     let width = grid.width;
     let height = grid.height;
     let colours: Vec<Colour> = endpoints.keys().cloned().collect();
+    let domain = propagate_candidates(grid, &endpoints, &colours)?;
     let mut var_map = HashMap::new();
     let mut dir_map = HashMap::new();
     let mut next_var = 1;
 
-    // Variable: cell (x, y) is color c
+    // Variable: cell (x, y) is color c, but only for colours propagation
+    // left in that cell's candidate set — eliminated (x, y, colour) triples
+    // get no variable at all, shrinking the formula.
     for y in 0..height {
         for x in 0..width {
+            let point = Point::new(x, y);
             for &colour in &colours {
-                var_map.insert((x, y, colour), next_var);
-                next_var += 1;
+                if domain[&point].contains(&colour) {
+                    var_map.insert((x, y, colour), next_var);
+                    next_var += 1;
+                }
             }
         }
     }
@@ -47,25 +163,28 @@ pub fn solve_sat(grid: &Grid) -> Option<Grid> {
     }
     let mut formula = CnfFormula::new();
 
-    // Each cell must be exactly one color
+    // Each cell must be exactly one color, restricted to the colours that
+    // survived propagation for that cell.
     for y in 0..height {
         for x in 0..width {
-            let mut clause = Vec::new();
-            for &colour in &colours {
-                clause.push(var_map[&(x, y, colour)] as isize);
-            }
+            let point = Point::new(x, y);
+            let present: Vec<i32> = colours
+                .iter()
+                .filter(|c| domain[&point].contains(c))
+                .map(|c| var_map[&(x, y, *c)])
+                .collect();
             formula.add_clause(
-                &clause
+                &present
                     .iter()
-                    .map(|&v| varisat::Lit::from_dimacs(v))
+                    .map(|&v| varisat::Lit::from_dimacs(v as isize))
                     .collect::<Vec<_>>(),
             );
             // At most one color per cell
-            for i in 0..colours.len() {
-                for j in i + 1..colours.len() {
+            for i in 0..present.len() {
+                for j in i + 1..present.len() {
                     formula.add_clause(&[
-                        varisat::Lit::from_dimacs(-(var_map[&(x, y, colours[i])] as isize)),
-                        varisat::Lit::from_dimacs(-(var_map[&(x, y, colours[j])] as isize)),
+                        varisat::Lit::from_dimacs(-(present[i] as isize)),
+                        varisat::Lit::from_dimacs(-(present[j] as isize)),
                     ]);
                 }
             }
@@ -74,45 +193,36 @@ pub fn solve_sat(grid: &Grid) -> Option<Grid> {
 
     // Endpoint constraints
     for (&colour, &(p1, p2)) in &endpoints {
-        // Endpoint must be its color
+        // Endpoint must be its color (its domain is already the singleton
+        // {colour}, but keeping this explicit matches the SAT encoding
+        // before propagation was introduced).
         formula.add_clause(&[varisat::Lit::from_dimacs(
             var_map[&(p1.x, p1.y, colour)] as isize,
         )]);
         formula.add_clause(&[varisat::Lit::from_dimacs(
             var_map[&(p2.x, p2.y, colour)] as isize,
         )]);
-        // Endpoint must not be any other color
-        for &other in &colours {
-            if other != colour {
-                formula.add_clause(&[varisat::Lit::from_dimacs(
-                    -(var_map[&(p1.x, p1.y, other)] as isize),
-                )]);
-                formula.add_clause(&[varisat::Lit::from_dimacs(
-                    -(var_map[&(p2.x, p2.y, other)] as isize),
-                )]);
-            }
-        }
-        // Each endpoint must have exactly one neighbor of its color
+        // Each endpoint must have exactly one neighbor of its color, among
+        // the neighbors propagation left able to carry it.
         for &point in &[p1, p2] {
-            let neighbors = point.neighbors(width, height);
-            let mut neighbor_vars = Vec::new();
-            for n in neighbors {
-                neighbor_vars.push(var_map[&(n.x, n.y, colour)] as isize);
-            }
+            let neighbor_vars: Vec<i32> = point
+                .neighbors(width, height)
+                .into_iter()
+                .filter_map(|n| var_map.get(&(n.x, n.y, colour)).copied())
+                .collect();
             // At least one neighbor
-            let clause = neighbor_vars.clone();
             formula.add_clause(
-                &clause
+                &neighbor_vars
                     .iter()
-                    .map(|&v| varisat::Lit::from_dimacs(v))
+                    .map(|&v| varisat::Lit::from_dimacs(v as isize))
                     .collect::<Vec<_>>(),
             );
             // At most one neighbor
             for i in 0..neighbor_vars.len() {
                 for j in i + 1..neighbor_vars.len() {
                     formula.add_clause(&[
-                        varisat::Lit::from_dimacs(-neighbor_vars[i]),
-                        varisat::Lit::from_dimacs(-neighbor_vars[j]),
+                        varisat::Lit::from_dimacs(-(neighbor_vars[i] as isize)),
+                        varisat::Lit::from_dimacs(-(neighbor_vars[j] as isize)),
                     ]);
                 }
             }
@@ -159,7 +269,7 @@ pub fn solve_sat(grid: &Grid) -> Option<Grid> {
             if matches!(grid.get(point), Cell::Endpoint { .. }) {
                 continue;
             }
-            for &colour in &colours {
+            for &colour in colours.iter().filter(|c| domain[&point].contains(c)) {
                 let cell_var = var_map[&(x, y, colour)] as isize;
                 for &dir_type in &DIR_TYPES {
                     if let Some(&dir_var) = dir_map.get(&(x, y, dir_type)) {
@@ -200,10 +310,23 @@ pub fn solve_sat(grid: &Grid) -> Option<Grid> {
                         if valid_neighbors.len() != 2 {
                             continue;
                         }
+                        // A neighbor with no variable for this colour was
+                        // pruned by propagation, i.e. it can never carry
+                        // it: the dir_type/colour combo that would require
+                        // it is simply forbidden outright.
                         let n1_var =
-                            var_map[&(valid_neighbors[0].0, valid_neighbors[0].1, colour)] as isize;
+                            var_map.get(&(valid_neighbors[0].0, valid_neighbors[0].1, colour));
                         let n2_var =
-                            var_map[&(valid_neighbors[1].0, valid_neighbors[1].1, colour)] as isize;
+                            var_map.get(&(valid_neighbors[1].0, valid_neighbors[1].1, colour));
+                        if n1_var.is_none() || n2_var.is_none() {
+                            formula.add_clause(&[
+                                varisat::Lit::from_dimacs(-(dir_var as isize)),
+                                varisat::Lit::from_dimacs(-cell_var),
+                            ]);
+                            continue;
+                        }
+                        let n1_var = *n1_var.unwrap() as isize;
+                        let n2_var = *n2_var.unwrap() as isize;
                         // yi,t -> (xi,u <-> xj,u) and (xi,u <-> xk,u)
                         // (¬yi,t ∨ ¬xi,u ∨ xj,u)
                         formula.add_clause(&[
@@ -234,12 +357,13 @@ pub fn solve_sat(grid: &Grid) -> Option<Grid> {
                         for n in all_neighbors {
                             let n_coord = (n.x, n.y);
                             if !valid_neighbors.contains(&n_coord) {
-                                let n_var = var_map[&(n.x, n.y, colour)] as isize;
-                                formula.add_clause(&[
-                                    varisat::Lit::from_dimacs(-(dir_var as isize)),
-                                    varisat::Lit::from_dimacs(-cell_var),
-                                    varisat::Lit::from_dimacs(-n_var),
-                                ]);
+                                if let Some(&n_var) = var_map.get(&(n.x, n.y, colour)) {
+                                    formula.add_clause(&[
+                                        varisat::Lit::from_dimacs(-(dir_var as isize)),
+                                        varisat::Lit::from_dimacs(-cell_var),
+                                        varisat::Lit::from_dimacs(-(n_var as isize)),
+                                    ]);
+                                }
                             }
                         }
                     }
@@ -248,40 +372,284 @@ pub fn solve_sat(grid: &Grid) -> Option<Grid> {
         }
     }
 
-    let mut solver = Solver::new();
-    solver.add_formula(&formula);
-    if solver.solve().unwrap_or(false) {
-        if let Some(model) = solver.model() {
-            let mut new_grid = grid.clone();
-            for y in 0..height {
-                for x in 0..width {
-                    let mut found = false;
-                    for &colour in &colours {
-                        let var: isize = var_map[&(x, y, colour)];
-                        let idx = (var.abs() - 1) as usize;
-                        if idx < model.len() && model[idx].is_positive() {
-                            let point = Point::new(x, y);
-                            match grid.get(point) {
-                                Cell::Endpoint { .. } => {
-                                    new_grid.set(point, Cell::Endpoint { colour })
-                                }
-                                _ => new_grid.set(point, Cell::Path { colour }),
-                            }
-                            found = true;
-                            break;
+    Some(Encoding {
+        formula,
+        var_map,
+        colours,
+    })
+}
+
+/// Reconstructs the coloured grid implied by a satisfying `model`, or `None`
+/// if some cell was left without an assigned colour (which should not
+/// happen for a well-formed encoding, but is checked defensively).
+fn grid_from_model(grid: &Grid, encoding: &Encoding, model: &[Lit]) -> Option<Grid> {
+    let mut new_grid = grid.clone();
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let mut found = false;
+            for &colour in &encoding.colours {
+                let var = match encoding.var_map.get(&(x, y, colour)) {
+                    Some(&var) => var as isize,
+                    None => continue,
+                };
+                let idx = (var.abs() - 1) as usize;
+                if idx < model.len() && model[idx].is_positive() {
+                    let point = Point::new(x, y);
+                    match grid.get(point) {
+                        Cell::Endpoint { .. } => {
+                            new_grid.set(point, Cell::Endpoint { colour, solved: true })
                         }
+                        _ => new_grid.set(point, Cell::Path { colour, solved: true }),
                     }
-                    if !found {
-                        // If no color assigned, puzzle is unsolvable
-                        return None;
-                    }
+                    found = true;
+                    break;
                 }
             }
-            Some(new_grid)
-        } else {
-            None
+            if !found {
+                return None;
+            }
         }
+    }
+    Some(new_grid)
+}
+
+fn manhattan(a: Point, b: Point) -> usize {
+    (a.x as isize - b.x as isize).unsigned_abs() + (a.y as isize - b.y as isize).unsigned_abs()
+}
+
+/// A cached full solution that [`HintSession::next_hint`] reveals one cell
+/// at a time, so the player never has to re-solve the puzzle and never sees
+/// more than they asked for.
+pub struct HintSession {
+    puzzle: Grid,
+    solution: Grid,
+    revealed: HashSet<Point>,
+}
+
+impl HintSession {
+    /// Solves `grid` once via [`solve_sat`] and caches the result; returns
+    /// `None` if the puzzle has no solution to hint from.
+    pub fn new(grid: &Grid) -> Option<Self> {
+        let solution = solve_sat(grid)?;
+        Some(Self {
+            puzzle: grid.clone(),
+            solution,
+            revealed: HashSet::new(),
+        })
+    }
+
+    /// Reveals the colour of one more currently-empty cell, popped in a
+    /// stable order (nearest to an endpoint first), without spoiling the
+    /// rest of the solution. Returns `None` once every empty cell has been
+    /// revealed.
+    pub fn next_hint(&mut self) -> Option<(Point, Colour)> {
+        let endpoints = self.puzzle.get_endpoints();
+        let mut candidates: Vec<Point> = Vec::new();
+        for y in 0..self.puzzle.height {
+            for x in 0..self.puzzle.width {
+                let p = Point::new(x, y);
+                if matches!(self.puzzle.get(p), Cell::Empty) && !self.revealed.contains(&p) {
+                    candidates.push(p);
+                }
+            }
+        }
+        candidates.sort_by_key(|&p| {
+            endpoints
+                .values()
+                .flat_map(|&(a, b)| [a, b])
+                .map(|q| manhattan(p, q))
+                .min()
+                .unwrap_or(usize::MAX)
+        });
+
+        let chosen = *candidates.first()?;
+        self.revealed.insert(chosen);
+        let colour = self.solution.get(chosen).colour()?;
+        Some((chosen, colour))
+    }
+}
+
+/// Solves `grid` using `predictions` (a per-cell colour guess, e.g. from
+/// [`crate::puzzle_ai::warm_start_predictions`]) as SAT assumptions rather
+/// than hard clauses. Guesses that conflict are just assumptions the
+/// solver backtracks past, so a wrong prediction costs search effort but
+/// never turns a solvable puzzle unsolvable: if the warm-started solve
+/// comes back UNSAT, the assumptions are dropped and the puzzle is solved
+/// again from scratch, retaining SAT's completeness guarantee.
+pub fn solve_sat_with_warm_start(grid: &Grid, predictions: &[(Point, Colour)]) -> Option<Grid> {
+    let encoding = build_formula(grid)?;
+    let mut solver = Solver::new();
+    solver.add_formula(&encoding.formula);
+
+    let assumptions: Vec<Lit> = predictions
+        .iter()
+        .filter_map(|&(point, colour)| {
+            encoding
+                .var_map
+                .get(&(point.x, point.y, colour))
+                .map(|&var| Lit::from_dimacs(var as isize))
+        })
+        .collect();
+
+    solver.assume(&assumptions);
+    if solver.solve().unwrap_or(false) {
+        let model = solver.model()?;
+        return grid_from_model(grid, &encoding, &model);
+    }
+
+    solver.assume(&[]);
+    if solver.solve().unwrap_or(false) {
+        let model = solver.model()?;
+        grid_from_model(grid, &encoding, &model)
+    } else {
+        None
+    }
+}
+
+pub fn solve_sat(grid: &Grid) -> Option<Grid> {
+    let encoding = build_formula(grid)?;
+    let mut solver = Solver::new();
+    solver.add_formula(&encoding.formula);
+    if solver.solve().unwrap_or(false) {
+        let model = solver.model()?;
+        grid_from_model(grid, &encoding, &model)
     } else {
         None
     }
 }
+
+/// Enumerates distinct solutions to `grid` by solving it, adding a
+/// *blocking clause* that rules the found assignment back out, and solving
+/// again, stopping once `max_solutions` have been collected (`None` means
+/// "as many as exist"). Only the `var_map` cell-colour variables are
+/// blocked (direction variables are derived from them), so each clause is
+/// `width * height` literals long.
+pub fn solve_sat_enumerate(grid: &Grid, max_solutions: Option<usize>) -> Vec<Grid> {
+    let cap = max_solutions.unwrap_or(usize::MAX).max(1);
+    let encoding = match build_formula(grid) {
+        Some(encoding) => encoding,
+        None => return Vec::new(),
+    };
+    let mut solver = Solver::new();
+    solver.add_formula(&encoding.formula);
+
+    let mut solutions = Vec::new();
+
+    while solutions.len() < cap {
+        if !solver.solve().unwrap_or(false) {
+            break;
+        }
+        let model = match solver.model() {
+            Some(model) => model,
+            None => break,
+        };
+        match grid_from_model(grid, &encoding, &model) {
+            Some(solution) => solutions.push(solution),
+            None => break,
+        }
+
+        // Block this exact assignment so the next `solve()` call is forced
+        // to find a different one, if any exists: for every cell, take the
+        // colour variable that was true and add its negation.
+        let mut blocking_clause = Vec::with_capacity(grid.width * grid.height);
+        for y in 0..grid.height {
+            for x in 0..grid.width {
+                for &colour in &encoding.colours {
+                    let var = match encoding.var_map.get(&(x, y, colour)) {
+                        Some(&var) => var,
+                        None => continue,
+                    };
+                    let idx = (var.abs() - 1) as usize;
+                    if idx < model.len() && model[idx].is_positive() {
+                        blocking_clause.push(Lit::from_dimacs(-(var as isize)));
+                        break;
+                    }
+                }
+            }
+        }
+        solver.add_clause(&blocking_clause);
+    }
+
+    solutions
+}
+
+/// Checks whether `grid` has exactly one solution, via [`solve_sat_enumerate`]
+/// capped at 2: stopping as soon as a second solution turns up is enough to
+/// settle `Multiple` without enumerating further.
+pub fn solve_sat_unique(grid: &Grid) -> SolveOutcome {
+    let mut solutions = solve_sat_enumerate(grid, Some(2));
+    match solutions.len() {
+        0 => SolveOutcome::Unsolvable,
+        1 => SolveOutcome::Unique(solutions.remove(0)),
+        _ => SolveOutcome::Multiple,
+    }
+}
+
+/// Checks whether the puzzle is still solvable with the player's placed
+/// `Cell::Path` cells held fixed, by passing them to the SAT solver as
+/// assumptions (the same mechanism [`solve_sat_with_warm_start`] uses for
+/// AI predictions) rather than hard clauses: if the solver can't satisfy
+/// the formula under those assumptions, the player has painted themselves
+/// into a dead end. Returns `false` if the grid has no valid encoding at
+/// all (propagation leaves some cell with no candidate colour), or if a
+/// placed cell holds a colour propagation already ruled out there.
+pub fn is_partial_solvable(grid: &Grid) -> bool {
+    let encoding = match build_formula(grid) {
+        Some(encoding) => encoding,
+        None => return false,
+    };
+
+    let assumptions: Option<Vec<Lit>> = grid
+        .cells
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| (x, y, cell)))
+        .filter_map(|(x, y, cell)| match cell {
+            Cell::Path { colour, .. } => Some(
+                encoding
+                    .var_map
+                    .get(&(x, y, *colour))
+                    .map(|&var| Lit::from_dimacs(var as isize)),
+            ),
+            _ => None,
+        })
+        .collect();
+    let Some(assumptions) = assumptions else {
+        return false;
+    };
+
+    let mut solver = Solver::new();
+    solver.add_formula(&encoding.formula);
+    solver.assume(&assumptions);
+    solver.solve().unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn propagate_candidates_prunes_a_cell_pinned_by_a_corner_endpoint() {
+        // 3x2 grid: Red endpoints at (0,0)-(2,1), Blue at (2,0)-(1,1).
+        // The Blue corner (2,0) has only two neighbours, (1,0) and the Red
+        // endpoint (2,1), so (1,0) is the only cell that can carry Blue out
+        // of it and its candidate set narrows from {Red, Blue} to {Blue}.
+        let mut endpoints = HashMap::new();
+        endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(2, 1)));
+        endpoints.insert(Colour::Blue, (Point::new(2, 0), Point::new(1, 1)));
+        let grid = Grid::new(3, 2, &endpoints);
+        let colours = vec![Colour::Red, Colour::Blue];
+
+        let domain = propagate_candidates(&grid, &endpoints, &colours).unwrap();
+        assert_eq!(domain[&Point::new(1, 0)], HashSet::from([Colour::Blue]));
+    }
+
+    #[test]
+    fn propagate_candidates_returns_none_when_no_colour_can_fill_a_cell() {
+        // An empty cell with no surviving candidate colour means the puzzle
+        // can't be filled; simulate that directly with an empty colour list
+        // against a grid that still has a cell to fill.
+        let grid = Grid::new(2, 1, &HashMap::new());
+        assert!(propagate_candidates(&grid, &HashMap::new(), &[]).is_none());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::app::{App, AppState};
+use crate::backtracking::brute_force_with_progress;
 use crate::board::{Cell, Grid};
-use crossterm::event::{self, poll, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{self, poll, Event as CEvent, KeyCode, KeyEvent, KeyEventKind};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -9,36 +10,78 @@ use ratatui::{
     Frame,
 };
 use std::io;
+use std::sync::mpsc;
+use std::thread;
 use std::time::Duration;
 
+const TICK_RATE: Duration = Duration::from_millis(100);
+
+/// Unified event stream the UI loop blocks on: keyboard input from a reader
+/// thread, a tick to keep redrawing while idle, and progress/completion
+/// messages from a solver thread.
+enum Event {
+    Input(KeyEvent),
+    Tick,
+    SolveProgress { steps: usize, grid_snapshot: Grid },
+    SolveDone(crate::backtracking::SolveResult),
+    RaceDone(Option<crate::astar::ParallelSolveResult>),
+    BenchmarkDone(Vec<crate::app::SolverBenchmark>),
+}
+
 pub fn run(app: &mut App) -> io::Result<()> {
     let mut terminal = ratatui::init();
     terminal.clear()?;
 
+    let (tx, rx) = mpsc::channel::<Event>();
+    spawn_input_thread(tx.clone());
+
     loop {
         terminal.draw(|frame| ui(frame, app))?;
 
-        // If solving, step through the algorithm
-        if app.state == AppState::Solving {
-            app.step_solve();
-            // Small delay to visualize steps
-            std::thread::sleep(Duration::from_millis(10));
-            
-            // Check for user input to cancel
-            if poll(Duration::from_millis(0))? {
-                if let Event::Key(key) = event::read()? {
-                    if key.kind == KeyEventKind::Press {
+        match rx.recv() {
+            Ok(Event::Input(key)) => {
+                if key.kind == KeyEventKind::Press {
+                    if app.state == AppState::PuzzleSelection && key.code == KeyCode::Enter {
+                        app.load_puzzle();
+                        app.solve_puzzle();
+                        if let Some(grid) = app.current_grid.clone() {
+                            spawn_solver_thread(grid, tx.clone());
+                        }
+                    } else if app.state == AppState::PuzzleSelection
+                        && key.code == KeyCode::Char('p')
+                    {
+                        app.load_puzzle();
+                        app.start_race();
+                        if let Some(grid) = app.current_grid.clone() {
+                            spawn_race_thread(grid, tx.clone());
+                        }
+                    } else if app.state == AppState::PuzzleSelection
+                        && key.code == KeyCode::Char('c')
+                    {
+                        app.load_puzzle();
+                        app.start_benchmark();
+                        if let Some(grid) = app.current_grid.clone() {
+                            spawn_benchmark_thread(grid, app.model_path.clone(), tx.clone());
+                        }
+                    } else {
                         handle_key_event(app, key);
                     }
                 }
             }
-        } else {
-            // Normal event handling
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    handle_key_event(app, key);
-                }
+            Ok(Event::Tick) => {}
+            Ok(Event::SolveProgress { steps, grid_snapshot }) => {
+                app.apply_progress(steps, grid_snapshot);
+            }
+            Ok(Event::SolveDone(result)) => {
+                app.apply_done(result);
+            }
+            Ok(Event::RaceDone(result)) => {
+                app.apply_race_done(result);
             }
+            Ok(Event::BenchmarkDone(results)) => {
+                app.apply_benchmark_done(results);
+            }
+            Err(_) => break,
         }
 
         if app.should_quit {
@@ -50,15 +93,69 @@ pub fn run(app: &mut App) -> io::Result<()> {
     Ok(())
 }
 
+/// Reads keyboard input off the render thread so a blocking solve can't
+/// freeze the UI: pushes `Input` as keys arrive and `Tick` while idle.
+fn spawn_input_thread(tx: mpsc::Sender<Event>) {
+    thread::spawn(move || loop {
+        let has_event = poll(TICK_RATE).unwrap_or(false);
+        if has_event {
+            if let Ok(CEvent::Key(key)) = event::read() {
+                if tx.send(Event::Input(key)).is_err() {
+                    return;
+                }
+            }
+        } else if tx.send(Event::Tick).is_err() {
+            return;
+        }
+    });
+}
+
+/// Runs the solve on its own thread so large grids solve at full speed
+/// instead of being throttled by the render loop, streaming a
+/// `SolveProgress` for every path placement the search tries so the solving screen
+/// animates the fill live instead of jumping straight from empty to done.
+fn spawn_solver_thread(grid: Grid, tx: mpsc::Sender<Event>) {
+    thread::spawn(move || {
+        let mut grid = grid;
+        let progress_tx = tx.clone();
+        let result = brute_force_with_progress(&mut grid, &mut |steps, grid_snapshot| {
+            let _ = progress_tx.send(Event::SolveProgress {
+                steps,
+                grid_snapshot: grid_snapshot.clone(),
+            });
+        });
+
+        let _ = tx.send(Event::SolveDone(result));
+    });
+}
+
+/// Runs `astar::solve_parallel` on its own thread so racing the solvers
+/// can't freeze the render loop the way a direct, in-handler call would.
+fn spawn_race_thread(grid: Grid, tx: mpsc::Sender<Event>) {
+    thread::spawn(move || {
+        let result = crate::astar::solve_parallel(&grid);
+        let _ = tx.send(Event::RaceDone(result));
+    });
+}
+
+/// Runs `app::run_benchmark` on its own thread so comparing solvers can't
+/// freeze the render loop the way a direct, in-handler call would.
+fn spawn_benchmark_thread(grid: Grid, model_path: String, tx: mpsc::Sender<Event>) {
+    thread::spawn(move || {
+        let results = crate::app::run_benchmark(&grid, &model_path);
+        let _ = tx.send(Event::BenchmarkDone(results));
+    });
+}
+
 fn handle_key_event(app: &mut App, key: KeyEvent) {
     match app.state {
         AppState::PuzzleSelection => match key.code {
             KeyCode::Char('q') | KeyCode::Esc => app.quit(),
             KeyCode::Down | KeyCode::Char('j') => app.next_puzzle(),
             KeyCode::Up | KeyCode::Char('k') => app.previous_puzzle(),
-            KeyCode::Enter => {
+            KeyCode::Char('h') => {
                 app.load_puzzle();
-                app.solve_puzzle();
+                app.start_hints();
             }
             _ => {}
         },
@@ -70,6 +167,25 @@ fn handle_key_event(app: &mut App, key: KeyEvent) {
             KeyCode::Char('b') | KeyCode::Backspace => app.back_to_selection(),
             _ => {}
         },
+        AppState::Benchmark => match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => app.quit(),
+            KeyCode::Char('b') | KeyCode::Backspace => app.back_to_selection(),
+            _ => {}
+        },
+        AppState::Hinting => match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => app.quit(),
+            KeyCode::Char('b') | KeyCode::Backspace => app.back_to_selection(),
+            KeyCode::Char('h') => app.reveal_hint(),
+            KeyCode::Left => app.move_cursor(-1, 0),
+            KeyCode::Right => app.move_cursor(1, 0),
+            KeyCode::Up => app.move_cursor(0, -1),
+            KeyCode::Down => app.move_cursor(0, 1),
+            KeyCode::Char('c') => app.cycle_fill_colour(),
+            KeyCode::Enter | KeyCode::Char(' ') => app.place_at_cursor(),
+            KeyCode::Char('x') => app.clear_at_cursor(),
+            KeyCode::Char('v') => app.check_partial_solvable(),
+            _ => {}
+        },
     }
 }
 
@@ -89,6 +205,8 @@ fn ui(frame: &mut Frame, app: &App) {
         AppState::PuzzleSelection => render_puzzle_selection(frame, chunks[1], app),
         AppState::Solving => render_solving(frame, chunks[1], app),
         AppState::ViewingSolution => render_solution(frame, chunks[1], app),
+        AppState::Benchmark => render_benchmark(frame, chunks[1], app),
+        AppState::Hinting => render_hinting(frame, chunks[1], app),
     }
 
     render_footer(frame, chunks[2], app);
@@ -143,6 +261,9 @@ fn render_puzzle_selection(frame: &mut Frame, area: Rect, app: &App) {
         )),
         Line::from(""),
         Line::from("Press Enter to solve"),
+        Line::from("Press c to compare solvers"),
+        Line::from("Press p to race solvers"),
+        Line::from("Press h for hints"),
         Line::from("Press q or Esc to quit"),
     ];
 
@@ -161,23 +282,21 @@ fn render_solving(frame: &mut Frame, area: Rect, app: &App) {
 
     // Status info
     let elapsed = app.solve_start_time.map(|start| start.elapsed()).unwrap_or_default();
-    let steps = *app.steps_count.lock().unwrap();
     let status_text = format!(
         "Solving... | Steps: {} | Elapsed: {:.2}s",
-        steps,
+        app.steps_count,
         elapsed.as_secs_f64()
     );
-    
+
     let status = Paragraph::new(status_text)
         .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
         .alignment(Alignment::Center)
         .block(Block::default().title("Status").borders(Borders::ALL));
-    
+
     frame.render_widget(status, chunks[0]);
 
     // Show current solving grid
-    let solving_grid_lock = app.solving_grid.lock().unwrap();
-    if let Some(grid) = solving_grid_lock.as_ref() {
+    if let Some(grid) = app.solving_grid.as_ref() {
         render_grid(frame, chunks[1], grid);
     } else {
         let text = Paragraph::new("Initializing...")
@@ -196,7 +315,7 @@ fn render_solution(frame: &mut Frame, area: Rect, app: &App) {
 
     let info_text = if let (Some(duration), Some(result)) = (&app.solve_duration, &app.solve_result) {
         format!(
-            "Puzzle: {} | Solver: Recursive Backtracking | Time: {:?} | Result: {}",
+            "Puzzle: {} | Time: {:?} | Result: {}",
             app.puzzle_files[app.selected_puzzle_index],
             duration,
             result
@@ -217,6 +336,68 @@ fn render_solution(frame: &mut Frame, area: Rect, app: &App) {
     }
 }
 
+fn render_benchmark(frame: &mut Frame, area: Rect, app: &App) {
+    use ratatui::widgets::BarChart;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    let steps_data: Vec<(&str, u64)> = app
+        .benchmark_results
+        .iter()
+        .map(|b| (b.label.as_str(), b.cells_filled))
+        .collect();
+    let millis_data: Vec<(&str, u64)> = app
+        .benchmark_results
+        .iter()
+        .map(|b| (b.label.as_str(), b.millis))
+        .collect();
+
+    let steps_chart = BarChart::default()
+        .block(Block::default().title("Cells Filled").borders(Borders::ALL))
+        .data(&steps_data)
+        .bar_width(9)
+        .bar_gap(3)
+        .value_style(Style::default().fg(Color::Black).bg(Color::Green))
+        .label_style(Style::default().fg(Color::Green))
+        .bar_style(Style::default().fg(Color::Green));
+
+    let millis_chart = BarChart::default()
+        .block(Block::default().title("Time (ms)").borders(Borders::ALL))
+        .data(&millis_data)
+        .bar_width(9)
+        .bar_gap(3)
+        .value_style(Style::default().fg(Color::Black).bg(Color::Cyan))
+        .label_style(Style::default().fg(Color::Cyan))
+        .bar_style(Style::default().fg(Color::Cyan));
+
+    frame.render_widget(steps_chart, chunks[0]);
+    frame.render_widget(millis_chart, chunks[1]);
+}
+
+fn render_hinting(frame: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(area);
+
+    if let Some(grid) = &app.hint_grid {
+        render_grid_with_cursor(frame, chunks[0], grid, app.cursor);
+    }
+
+    let selected = app.fill_colours.get(app.fill_colour_index);
+    let status = match selected {
+        Some(colour) => format!("Placing: {:?} | {}", colour, app.current_message),
+        None => app.current_message.clone(),
+    };
+    let status_widget = Paragraph::new(status)
+        .alignment(Alignment::Center)
+        .block(Block::default().title("Status").borders(Borders::ALL));
+    frame.render_widget(status_widget, chunks[1]);
+}
+
 fn render_grid(frame: &mut Frame, area: Rect, grid: &Grid) {
     let mut lines = Vec::new();
     
@@ -225,8 +406,8 @@ fn render_grid(frame: &mut Frame, area: Rect, grid: &Grid) {
         for cell in row {
             let (ch, color) = match cell {
                 Cell::Empty => ('.', Color::DarkGray),
-                Cell::Endpoint { colour } => ('O', cell_color(colour)),
-                Cell::Path { colour } => ('o', cell_color(colour)),
+                Cell::Endpoint { colour, .. } => ('O', cell_color(colour)),
+                Cell::Path { colour, .. } => ('o', cell_color(colour)),
             };
             spans.push(Span::styled(
                 format!("{} ", ch),
@@ -243,6 +424,35 @@ fn render_grid(frame: &mut Frame, area: Rect, grid: &Grid) {
     frame.render_widget(grid_widget, area);
 }
 
+/// Same rendering as [`render_grid`], but reverses the cell under `cursor`
+/// so the player can see where [`App::place_at_cursor`] will land.
+fn render_grid_with_cursor(frame: &mut Frame, area: Rect, grid: &Grid, cursor: crate::board::Point) {
+    let mut lines = Vec::new();
+
+    for (y, row) in grid.cells.iter().enumerate() {
+        let mut spans = Vec::new();
+        for (x, cell) in row.iter().enumerate() {
+            let (ch, color) = match cell {
+                Cell::Empty => ('.', Color::DarkGray),
+                Cell::Endpoint { colour, .. } => ('O', cell_color(colour)),
+                Cell::Path { colour, .. } => ('o', cell_color(colour)),
+            };
+            let mut style = Style::default().fg(color);
+            if cursor.x == x && cursor.y == y {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            spans.push(Span::styled(format!("{} ", ch), style));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    let grid_widget = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .block(Block::default().title("Solution").borders(Borders::ALL));
+
+    frame.render_widget(grid_widget, area);
+}
+
 fn cell_color(colour: &crate::board::Colour) -> Color {
     use crate::board::Colour;
     match colour {
@@ -268,10 +478,14 @@ fn cell_color(colour: &crate::board::Colour) -> Color {
 fn render_footer(frame: &mut Frame, area: Rect, app: &App) {
     let help_text = match app.state {
         AppState::PuzzleSelection => {
-            "↑/↓: Navigate | Enter: Solve | q/Esc: Quit"
+            "↑/↓: Navigate | Enter: Solve | c: Compare Solvers | p: Race Solvers | h: Hints | q/Esc: Quit"
         }
         AppState::Solving => "Solving in progress...",
         AppState::ViewingSolution => "b/Backspace: Back to Menu | q/Esc: Quit",
+        AppState::Benchmark => "b/Backspace: Back to Menu | q/Esc: Quit",
+        AppState::Hinting => {
+            "h: Next Hint | Arrows: Move | Space/Enter: Place | x: Clear | c: Colour | v: Verify | b/Backspace: Back to Menu | q/Esc: Quit"
+        }
     };
 
     let footer = Paragraph::new(help_text)
@@ -0,0 +1,14 @@
+use flowrs::astar::{solve_beam, BranchHeuristic};
+use flowrs::{Colour, Grid, Point};
+use std::collections::HashMap;
+
+#[test]
+fn beam_converges_on_a_small_solvable_board() {
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(2, 0)));
+    let grid = Grid::new(3, 1, &endpoints);
+
+    let solved = solve_beam(&grid, 4, 3, BranchHeuristic::default(), None);
+
+    assert!(solved.is_solved(&endpoints));
+}
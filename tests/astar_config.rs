@@ -0,0 +1,22 @@
+use flowrs::astar::{solve_astar_with, SolverConfig};
+use flowrs::{Cell, Colour, Grid, Point};
+use std::collections::HashMap;
+
+#[test]
+fn max_nodes_zero_bails_immediately_with_best_partial() {
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(2, 0)));
+    let grid = Grid::new(3, 1, &endpoints);
+
+    let config = SolverConfig {
+        max_nodes: Some(0),
+        ..Default::default()
+    };
+    let results = solve_astar_with(&grid, &config);
+
+    assert_eq!(results.len(), 1, "should fall back to a single best-partial grid");
+    assert!(
+        matches!(results[0].get(Point::new(1, 0)), Cell::Empty),
+        "a zero node budget shouldn't expand any nodes"
+    );
+}
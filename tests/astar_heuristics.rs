@@ -0,0 +1,29 @@
+use flowrs::astar::{solve_astar_with, BranchHeuristic, SolverConfig};
+use flowrs::{Colour, Grid, Point};
+use std::collections::HashMap;
+
+#[test]
+fn every_branch_heuristic_still_finds_a_valid_solution() {
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(3, 0)));
+    endpoints.insert(Colour::Blue, (Point::new(0, 1), Point::new(3, 1)));
+    let grid = Grid::new(4, 2, &endpoints);
+
+    for branch_heuristic in [
+        BranchHeuristic::Fewest,
+        BranchHeuristic::Manhattan,
+        BranchHeuristic::MostConstrainedGoal,
+        BranchHeuristic::Hybrid,
+    ] {
+        let config = SolverConfig {
+            branch_heuristic,
+            ..Default::default()
+        };
+        let results = solve_astar_with(&grid, &config);
+
+        assert!(
+            results.iter().any(|solved| solved.is_solved(&endpoints)),
+            "{branch_heuristic:?} should still find a valid solution"
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use flowrs::astar::solve_parallel;
+use flowrs::{Colour, Grid, Point};
+use std::collections::HashMap;
+
+#[test]
+fn solve_parallel_finds_a_solved_board() {
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(2, 0)));
+    let grid = Grid::new(3, 1, &endpoints);
+
+    let result = solve_parallel(&grid).expect("a 1x3 strip should be solvable");
+    assert!(result.grid.is_solved(&endpoints));
+}
+
+#[test]
+fn solve_parallel_returns_none_on_an_unsolvable_board() {
+    // 2x2 grid with both colours on opposite diagonals: every cell is
+    // already an endpoint, and diagonal corners aren't adjacent, so neither
+    // colour can ever reach its other end.
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(1, 1)));
+    endpoints.insert(Colour::Blue, (Point::new(1, 0), Point::new(0, 1)));
+    let grid = Grid::new(2, 2, &endpoints);
+
+    assert!(solve_parallel(&grid).is_none());
+}
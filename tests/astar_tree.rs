@@ -0,0 +1,22 @@
+use flowrs::astar::{solve_astar_with_tree, SolverConfig};
+use flowrs::{Colour, Grid, Point};
+use std::collections::HashMap;
+
+#[test]
+fn tree_is_only_recorded_when_requested() {
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(2, 0)));
+    let grid = Grid::new(3, 1, &endpoints);
+
+    let (solutions, tree) = solve_astar_with_tree(&grid, &SolverConfig::default());
+    assert!(solutions[0].is_solved(&endpoints));
+    assert!(tree.is_none());
+
+    let config = SolverConfig {
+        record_search_tree: true,
+        ..Default::default()
+    };
+    let (solutions, tree) = solve_astar_with_tree(&grid, &config);
+    assert!(solutions[0].is_solved(&endpoints));
+    assert!(tree.is_some(), "record_search_tree should populate the tree");
+}
@@ -0,0 +1,27 @@
+use flowrs::app::run_benchmark;
+use flowrs::{Colour, Grid, Point};
+use std::collections::HashMap;
+
+#[test]
+fn run_benchmark_reports_all_three_solvers_in_order() {
+    // 4x1 strip with a single colour pair: trivially solvable by every solver.
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(3, 0)));
+    let grid = Grid::new(4, 1, &endpoints);
+
+    // No model on disk at this path, so the warm-start leg falls back to
+    // plain SAT (see `TorchModelBackend::load` returning `None`).
+    let results = run_benchmark(&grid, "no/such/model.pt");
+
+    assert_eq!(results.len(), 3);
+    let labels: Vec<&str> = results.iter().map(|r| r.label.as_str()).collect();
+    assert_eq!(labels, ["Backtracking", "SAT", "SAT + AI warm-start"]);
+
+    for result in &results {
+        assert_eq!(
+            result.cells_filled, 4,
+            "{} should fill every cell of a solvable board",
+            result.label
+        );
+    }
+}
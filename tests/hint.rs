@@ -0,0 +1,61 @@
+use flowrs::app::{App, AppState};
+use flowrs::{Cell, Colour, Grid, Point};
+use std::collections::HashMap;
+
+#[test]
+fn reveal_hint_fills_one_empty_cell_at_a_time_without_solving_the_board() {
+    // 4x1 strip with a single colour pair: the two middle cells are empty
+    // and must both become Red to connect the endpoints.
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(3, 0)));
+    let grid = Grid::new(4, 1, &endpoints);
+
+    let mut app = App::new();
+    app.current_grid = Some(grid);
+    app.start_hints();
+    assert_eq!(app.state, AppState::Hinting);
+
+    app.reveal_hint();
+    let hint_grid = app.hint_grid.as_ref().unwrap();
+    let revealed: Vec<Point> = [Point::new(1, 0), Point::new(2, 0)]
+        .into_iter()
+        .filter(|&p| matches!(hint_grid.get(p), Cell::Path { colour: Colour::Red, solved: false }))
+        .collect();
+    assert_eq!(revealed.len(), 1);
+
+    app.reveal_hint();
+    let hint_grid = app.hint_grid.as_ref().unwrap();
+    for p in [Point::new(1, 0), Point::new(2, 0)] {
+        assert!(matches!(
+            hint_grid.get(p),
+            Cell::Path { colour: Colour::Red, solved: false }
+        ));
+    }
+
+    // Every empty cell has now been revealed once; further calls are no-ops.
+    app.reveal_hint();
+    let hint_grid = app.hint_grid.as_ref().unwrap();
+    for p in [Point::new(1, 0), Point::new(2, 0)] {
+        assert!(matches!(
+            hint_grid.get(p),
+            Cell::Path { colour: Colour::Red, solved: false }
+        ));
+    }
+}
+
+#[test]
+fn start_hints_stays_on_puzzle_selection_when_unsolvable() {
+    // Both colours' endpoints occupy opposite diagonal corners on a 2x2
+    // board, so neither pair can ever connect.
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(1, 1)));
+    endpoints.insert(Colour::Blue, (Point::new(1, 0), Point::new(0, 1)));
+    let grid = Grid::new(2, 2, &endpoints);
+
+    let mut app = App::new();
+    app.current_grid = Some(grid);
+    app.start_hints();
+
+    assert_eq!(app.state, AppState::PuzzleSelection);
+    assert!(app.hint_session.is_none());
+}
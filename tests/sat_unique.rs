@@ -0,0 +1,64 @@
+use flowrs::sat::{solve_sat_enumerate, solve_sat_unique, SolveOutcome};
+use flowrs::{Colour, Grid, Point};
+use std::collections::HashMap;
+
+#[test]
+fn unique_when_only_one_solution_exists() {
+    // Fully pre-filled 4x1 strip: two adjacent colour pairs leave no empty
+    // cells, so there's exactly one way to fill the board (itself).
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(1, 0)));
+    endpoints.insert(Colour::Blue, (Point::new(2, 0), Point::new(3, 0)));
+    let grid = Grid::new(4, 1, &endpoints);
+
+    assert!(matches!(solve_sat_unique(&grid), SolveOutcome::Unique(_)));
+}
+
+#[test]
+fn multiple_when_two_colours_can_swap_rows() {
+    // 4x4 grid with Red spanning the top row's corners and Blue the bottom
+    // row's corners: either one can take its own row straight across while
+    // the other winds through the remaining three rows, so there are at
+    // least two valid fillings.
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(3, 0)));
+    endpoints.insert(Colour::Blue, (Point::new(0, 3), Point::new(3, 3)));
+    let grid = Grid::new(4, 4, &endpoints);
+
+    assert!(matches!(solve_sat_unique(&grid), SolveOutcome::Multiple));
+}
+
+#[test]
+fn unsolvable_when_endpoints_cannot_connect() {
+    // 2x2 grid with both colours on opposite diagonals: every cell is
+    // already an endpoint, and diagonal corners aren't adjacent, so neither
+    // colour can ever reach its other end.
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(1, 1)));
+    endpoints.insert(Colour::Blue, (Point::new(1, 0), Point::new(0, 1)));
+    let grid = Grid::new(2, 2, &endpoints);
+
+    assert!(matches!(solve_sat_unique(&grid), SolveOutcome::Unsolvable));
+}
+
+#[test]
+fn enumerate_caps_at_max_solutions_even_when_more_exist() {
+    // Same row-swap board as above, which has more than one solution: a cap
+    // of 1 should stop after the first instead of enumerating the rest.
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(3, 0)));
+    endpoints.insert(Colour::Blue, (Point::new(0, 3), Point::new(3, 3)));
+    let grid = Grid::new(4, 4, &endpoints);
+
+    assert_eq!(solve_sat_enumerate(&grid, Some(1)).len(), 1);
+}
+
+#[test]
+fn enumerate_returns_empty_when_unsolvable() {
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(1, 1)));
+    endpoints.insert(Colour::Blue, (Point::new(1, 0), Point::new(0, 1)));
+    let grid = Grid::new(2, 2, &endpoints);
+
+    assert!(solve_sat_enumerate(&grid, None).is_empty());
+}
@@ -0,0 +1,34 @@
+use flowrs::puzzle_ai::{warm_start_predictions, ModelBackend, TorchModelBackend};
+use flowrs::{Colour, Grid, Point};
+use std::collections::HashMap;
+
+#[test]
+fn torch_model_backend_load_returns_none_for_a_missing_path() {
+    assert!(TorchModelBackend::load("no/such/model.pt").is_none());
+}
+
+struct FakeBackend {
+    predictions: HashMap<Point, (Colour, f32)>,
+}
+
+impl ModelBackend for FakeBackend {
+    fn predict(&self, _grid: &Grid, _colours: &[Colour]) -> HashMap<Point, (Colour, f32)> {
+        self.predictions.clone()
+    }
+}
+
+#[test]
+fn warm_start_predictions_drops_guesses_below_the_confidence_threshold() {
+    let mut endpoints = HashMap::new();
+    endpoints.insert(Colour::Red, (Point::new(0, 0), Point::new(1, 0)));
+    let grid = Grid::new(2, 1, &endpoints);
+
+    let mut predictions = HashMap::new();
+    predictions.insert(Point::new(0, 0), (Colour::Red, 0.95));
+    predictions.insert(Point::new(1, 0), (Colour::Red, 0.5));
+    let backend = FakeBackend { predictions };
+
+    let warm_start = warm_start_predictions(&grid, &backend, &[Colour::Red], 0.9);
+
+    assert_eq!(warm_start, vec![(Point::new(0, 0), Colour::Red)]);
+}